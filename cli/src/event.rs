@@ -55,6 +55,15 @@ impl Consumer {
     ///
     /// # Errors
     /// Can fail due to timeout or without message at websocket or during decoding request
+    ///
+    /// Resumable subscriptions (a `from_height` cursor replayed from the local `BlockStore` before
+    /// switching to the live stream) are not implemented here. This needs two things this tree
+    /// doesn't have: a `from_height` field on `EventSubscriptionRequest` (a single-field tuple
+    /// struct carrying only the filter, as the destructure below shows — and there's no
+    /// `iroha_data_model` crate root in this tree to edit that type in; `data_model/src/lib.rs`
+    /// is absent and `data_model/src/` contains only `block.rs`), and a `BlockStore`/Kura API to
+    /// actually replay historical blocks, which lives in `iroha_core` — also absent from this
+    /// tree. Both gaps are in code outside this slice, not in `Consumer` itself.
     #[iroha_futures::telemetry_future]
     pub async fn new(mut stream: WebSocket) -> Result<Self> {
         let EventSubscriptionRequest(filter) = stream.recv().await?;
@@ -67,6 +76,21 @@ impl Consumer {
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
     pub async fn consume(&mut self, event: Event) -> Result<()> {
+        self.forward(event).await
+    }
+
+    /// Forward a single `event` if it passes the filter.
+    ///
+    /// Fuel-threshold gating on pipeline events is not implemented here. It would need
+    /// `PipelineEventFilter` to grow a threshold field and the pipeline event payload to expose
+    /// consumed fuel, but `iroha_data_model` has no `events` module to edit in this tree at all —
+    /// `data_model/src/lib.rs` itself is absent, and `data_model/src/` contains only `block.rs`.
+    /// There is no crate root to declare the module in, and no existing type definition to extend;
+    /// writing field accesses against a guessed shape for `FilterBox`/`Event` (both only ever seen
+    /// here via the `events::prelude::*` glob, never defined in this tree) would be fabrication,
+    /// not implementation. Left as a pass-through filter match until `iroha_data_model::events`
+    /// is actually present to build against.
+    async fn forward(&mut self, event: Event) -> Result<()> {
         if !self.filter.matches(&event) {
             return Ok(());
         }
@@ -1,22 +1,176 @@
 //! Types for representing securely printable secrets.
+//!
+//! `chacha20poly1305` and `zeroize` aren't declared in any `Cargo.toml` in this tree -- there is
+//! none to edit but `fuzz/Cargo.toml` (`crates/iroha` itself has no manifest here), the same gap as
+//! the crypto-crate dependencies added elsewhere in this series. The tests below are written as if
+//! the dependency were declared, so the coverage exists the moment that gap is closed.
 use std::fmt;
 
-use derive_more::Constructor;
+use chacha20poly1305::{
+    aead::{generic_array::typenum::Unsigned, Aead as _, KeyInit as _, OsRng},
+    AeadCore, ChaCha20Poly1305, Nonce,
+};
 use serde::{Deserialize, Serialize, Serializer};
+use zeroize::Zeroize as _;
 
-/// String sensitive to printing and serialization
-#[derive(Clone, Deserialize, Constructor)]
+/// String sensitive to printing and serialization.
+///
+/// The backing buffer is overwritten with zeros on drop so secrets (private keys, passwords) don't
+/// linger in freed heap memory. `Clone` is implemented explicitly rather than derived so every copy
+/// of a secret is a visible call site.
+#[derive(Deserialize)]
 pub struct SecretString(String);
 
 impl SecretString {
+    /// Wrap `secret` as a [`SecretString`].
+    ///
+    /// Prefer [`SecretString::take`] when you hold an owned `String` so the original binding is
+    /// cleared instead of left in memory.
+    pub fn new(secret: String) -> Self {
+        Self(secret)
+    }
+
+    /// Take ownership of `secret`'s buffer, leaving the caller's binding as an empty `String`.
+    ///
+    /// The buffer is moved, not copied, and is zeroized when the returned value is dropped.
+    pub fn take(secret: &mut String) -> Self {
+        let taken = std::mem::take(secret);
+        // The caller's `String` now owns an empty buffer; the moved-out one is held by us.
+        Self(taken)
+    }
+
     /// Returns underlying secret string
     pub fn expose_secret(&self) -> &str {
         &self.0
     }
 }
 
+impl Clone for SecretString {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        // Volatile zeroizing write the optimizer cannot elide.
+        self.0.zeroize();
+    }
+}
+
+/// A 256-bit symmetric key for sealing/unsealing a [`SecretString`].
+pub type SealingKey = [u8; 32];
+
+impl SecretString {
+    /// Encrypt this secret under `key`, producing a [`SealedSecretString`] that can be serialized
+    /// to disk and later restored via [`SealedSecretString::open`].
+    ///
+    /// Unlike the default redacted [`Serialize`] impl, the sealed form round-trips — but only ever
+    /// when a key is supplied explicitly here; plaintext is never written out.
+    ///
+    /// # Errors
+    /// Fails if AEAD encryption fails.
+    pub fn seal(&self, key: &SealingKey) -> Result<SealedSecretString, SealError> {
+        let cipher = ChaCha20Poly1305::new(key.into());
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, self.0.as_bytes())
+            .map_err(|_| SealError)?;
+        Ok(SealedSecretString {
+            nonce: nonce.to_vec(),
+            ciphertext,
+        })
+    }
+}
+
+/// Ciphertext + nonce form of a [`SecretString`], safe to persist and reload.
+///
+/// Serializes to its `nonce` and `ciphertext` (never plaintext); decrypt with
+/// [`open`](SealedSecretString::open) and the same [`SealingKey`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SealedSecretString {
+    #[serde(with = "hex::serde")]
+    nonce: Vec<u8>,
+    #[serde(with = "hex::serde")]
+    ciphertext: Vec<u8>,
+}
+
+impl SealedSecretString {
+    /// Decrypt back into a [`SecretString`] using `key`.
+    ///
+    /// # Errors
+    /// Fails if the nonce is malformed or decryption/authentication fails (wrong key or tampering).
+    pub fn open(&self, key: &SealingKey) -> Result<SecretString, SealError> {
+        let nonce_len = <<ChaCha20Poly1305 as AeadCore>::NonceSize as Unsigned>::USIZE;
+        if self.nonce.len() != nonce_len {
+            return Err(SealError);
+        }
+        let cipher = ChaCha20Poly1305::new(key.into());
+        let nonce = Nonce::from_slice(&self.nonce);
+        let plaintext = cipher
+            .decrypt(nonce, self.ciphertext.as_slice())
+            .map_err(|_| SealError)?;
+        let secret = String::from_utf8(plaintext).map_err(|_| SealError)?;
+        Ok(SecretString(secret))
+    }
+}
+
+impl fmt::Debug for SealedSecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Ciphertext is safe to print, but keep output terse and non-committal.
+        f.debug_struct("SealedSecretString").finish_non_exhaustive()
+    }
+}
+
+/// Sealing or unsealing a [`SecretString`] failed.
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to seal/unseal secret")]
+pub struct SealError;
+
 const REDACTED: &str = "[REDACTED]";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_string_is_zeroized_on_drop() {
+        // `String`'s heap buffer is addressable through a raw pointer even after the owning
+        // `SecretString` is dropped (the allocator doesn't have to reuse or unmap it immediately),
+        // so this reads it back afterwards to confirm `Drop` actually overwrote the bytes rather
+        // than just trusting the call happened.
+        let secret = "correct horse battery staple".to_owned();
+        let ptr = secret.as_ptr();
+        let len = secret.len();
+        {
+            let _guard = SecretString::new(secret);
+        }
+        // SAFETY: the allocation behind `ptr` is still valid (nothing else has reused it yet in
+        // this single-threaded test), only its contents have (or haven't) been zeroized.
+        let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+        assert!(bytes.iter().all(|&b| b == 0), "secret bytes were not zeroized on drop");
+    }
+
+    #[test]
+    fn seal_then_open_round_trips() {
+        let key: SealingKey = [7; 32];
+        let secret = SecretString::new("correct horse battery staple".to_owned());
+
+        let sealed = secret.seal(&key).expect("seal should succeed");
+        let opened = sealed.open(&key).expect("open with the same key should succeed");
+
+        assert_eq!(opened.expose_secret(), secret.expose_secret());
+    }
+
+    #[test]
+    fn open_with_wrong_key_fails() {
+        let secret = SecretString::new("correct horse battery staple".to_owned());
+        let sealed = secret.seal(&[1; 32]).expect("seal should succeed");
+
+        assert!(sealed.open(&[2; 32]).is_err());
+    }
+}
+
 impl Serialize for SecretString {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         REDACTED.serialize(serializer)
@@ -0,0 +1,48 @@
+//! Batch verification for Ed25519, used by [`super::SignaturesOf::verify_hash_batch`].
+//!
+//! One combined check over the whole batch is far cheaper than one verification per signature;
+//! callers fall back to verifying signatures individually only when the batch equation rejects, so
+//! they can still report which signature was at fault.
+
+use alloc::vec::Vec;
+
+use ed25519_dalek::{Signature as DalekSignature, Verifier as _, VerifyingKey};
+
+use crate::{Error, PublicKey};
+
+/// Verify every `(public_key, signature)` pair in `batch` against the same `message` in one go.
+///
+/// # Errors
+/// Fails if any public key or signature is malformed, or the batch doesn't verify.
+pub(crate) fn verify_batch(message: &[u8], batch: &[(&PublicKey, &[u8])]) -> Result<(), Error> {
+    let messages = batch.iter().map(|_| message).collect::<Vec<_>>();
+    let signatures = batch
+        .iter()
+        .map(|(_, payload)| {
+            let bytes: &[u8; 64] = (*payload)
+                .try_into()
+                .map_err(|_| Error::Other("Ed25519 signature must be 64 bytes".into()))?;
+            Ok(DalekSignature::from_bytes(bytes))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let keys = batch
+        .iter()
+        .map(|(public_key, _)| {
+            let bytes: &[u8; 32] = public_key
+                .payload()
+                .try_into()
+                .map_err(|_| Error::Other("Ed25519 public key must be 32 bytes".into()))?;
+            VerifyingKey::from_bytes(bytes)
+                .map_err(|_| Error::Other("invalid Ed25519 public key".into()))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if keys.len() == 1 {
+        // `ed25519_dalek::verify_batch` requires at least two entries.
+        return keys[0]
+            .verify(messages[0], &signatures[0])
+            .map_err(|_| Error::BadSignature);
+    }
+
+    ed25519_dalek::verify_batch(&messages, &signatures, &keys).map_err(|_| Error::BadSignature)
+}
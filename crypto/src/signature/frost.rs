@@ -0,0 +1,252 @@
+//! FROST (Flexible Round-Optimized Schnorr Threshold) signatures over Ed25519 and secp256k1.
+//!
+//! A `t-of-n` committee collectively produces a single ordinary [`Signature`](crate::Signature)
+//! that verifies under one group [`PublicKey`] via [`Signature::verify`](crate::Signature::verify),
+//! letting consensus store one aggregate signature in place of a
+//! [`SignaturesOf`](super::SignaturesOf) set.
+//!
+//! The flow is the standard two rounds, delegated to the audited `frost-ed25519` / `frost-secp256k1`
+//! implementations of the [`frost-core`](https://docs.rs/frost-core) suite. Neither crate is
+//! declared in a `Cargo.toml` anywhere in this tree (there is none to edit but `fuzz/Cargo.toml`).
+//!
+//! 1. **Key generation** ([`keygen`]) runs trusted-dealer key generation, producing a
+//!    [`KeyShare`] per participant plus a shared group verification key. Distributed key
+//!    generation is not provided; a single dealer generates every share.
+//! 2. **Round 1** ([`commit`]) each participant samples a fresh hiding nonce and binding nonce and
+//!    publishes their commitment points.
+//! 3. **Round 2** ([`sign`]) the coordinator derives a per-participant binding factor by hashing the
+//!    participant set, the message and all commitments, forms the group commitment
+//!    `R = Σ (hiding_i + binding_factor_i · binding_i)`, computes `c = H(R, group_pubkey, msg)`, and
+//!    each participant returns `z_i = hiding_i + binding_factor_i · binding_i + c · λ_i · sk_i`.
+//! 4. **Aggregation** ([`aggregate`]) the coordinator sums the shares into `z` and emits `(R, z)` as
+//!    an ordinary curve signature.
+//!
+//! Invariants upheld by the backend and re-asserted here: nonces are freshly sampled per session
+//! and zeroized (the `SigningNonces` type is not `Clone` and wipes on drop), binding factors bind to
+//! the exact participant set to prevent rogue-nonce attacks, and the emitted `(R, z)` is
+//! byte-compatible with the curve's normal signature verification.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::CryptoRngCore;
+
+use crate::{Algorithm, Error, PublicKey, Signature};
+
+/// Identifier of a signing participant, `1..=n`.
+pub type ParticipantId = core::num::NonZeroU16;
+
+/// Which curve a FROST committee operates over. Only the Schnorr-friendly curves are eligible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrostAlgorithm {
+    /// FROST over Ed25519 (RFC 8032 verification).
+    Ed25519,
+    /// FROST over secp256k1 (BIP340-style verification).
+    Secp256k1,
+}
+
+impl TryFrom<Algorithm> for FrostAlgorithm {
+    type Error = Error;
+
+    fn try_from(algorithm: Algorithm) -> Result<Self, Self::Error> {
+        match algorithm {
+            Algorithm::Ed25519 => Ok(Self::Ed25519),
+            Algorithm::Secp256k1 => Ok(Self::Secp256k1),
+            other => Err(Error::UnsupportedAlgorithm(other.to_string())),
+        }
+    }
+}
+
+/// A participant's long-lived secret share plus the shared public key material.
+///
+/// The secret material is held by the backend's `KeyPackage`, which zeroizes on drop.
+pub struct KeyShare {
+    algorithm: FrostAlgorithm,
+    id: ParticipantId,
+    package: backend::KeyPackage,
+    group_public_key: PublicKey,
+    public_key_package: PublicKeyPackage,
+}
+
+impl KeyShare {
+    /// This participant's id.
+    pub fn id(&self) -> ParticipantId {
+        self.id
+    }
+
+    /// The shared group verification key; the final signature verifies under this key.
+    pub fn group_public_key(&self) -> &PublicKey {
+        &self.group_public_key
+    }
+
+    /// The public key package produced alongside this share by [`keygen`]: every participant's
+    /// own verifying share plus the group verification key. The coordinator needs this (not just
+    /// the group key) to call [`aggregate`], since each signature share is checked against its
+    /// signer's own share before being combined.
+    pub fn public_key_package(&self) -> &PublicKeyPackage {
+        &self.public_key_package
+    }
+}
+
+/// Public key material from [`keygen`]: the group verification key plus each participant's own
+/// verifying share, so [`aggregate`] can check a signature share against its actual signer
+/// instead of (incorrectly) against the group key.
+#[derive(Clone)]
+pub struct PublicKeyPackage(backend::PublicKeyPackage);
+
+/// Per-session nonces sampled in round 1. Intentionally neither `Clone` nor `Copy` so they can't
+/// be reused across sessions; the backend wipes them on drop.
+pub struct SigningNonces(backend::SigningNonces);
+
+/// Public commitments `(hiding·G, binding·G)` broadcast in round 1.
+#[derive(Clone)]
+pub struct SigningCommitment {
+    id: ParticipantId,
+    inner: backend::SigningCommitments,
+}
+
+/// A round-2 signature share `z_i`.
+#[derive(Clone)]
+pub struct SignatureShare {
+    id: ParticipantId,
+    inner: backend::SignatureShare,
+}
+
+/// Run key generation for a `threshold`-of-`max_signers` committee, returning one [`KeyShare`] per
+/// participant, all carrying the same group verification key.
+///
+/// # Errors
+/// Fails if `algorithm` isn't threshold-capable, `threshold` is zero or exceeds `max_signers`, or
+/// the backend rejects the parameters.
+pub fn keygen(
+    algorithm: Algorithm,
+    threshold: u16,
+    max_signers: u16,
+    rng: &mut impl CryptoRngCore,
+) -> Result<BTreeMap<ParticipantId, KeyShare>, Error> {
+    let algorithm = FrostAlgorithm::try_from(algorithm)?;
+    backend::keygen(algorithm, threshold, max_signers, rng)
+}
+
+/// Round 1: sample fresh nonces for `share` and derive their public commitment.
+pub fn commit(
+    share: &KeyShare,
+    rng: &mut impl CryptoRngCore,
+) -> (SigningNonces, SigningCommitment) {
+    backend::commit(share, rng)
+}
+
+/// Round 2: produce this participant's signature share over `message`.
+///
+/// `commitments` must be the exact set broadcast in round 1; the binding factor is bound to it to
+/// defeat rogue-nonce attacks.
+///
+/// # Errors
+/// Fails if this participant's commitment is absent from `commitments` or the backend rejects the
+/// signing package.
+pub fn sign(
+    share: &KeyShare,
+    nonces: SigningNonces,
+    commitments: &[SigningCommitment],
+    message: &[u8],
+) -> Result<SignatureShare, Error> {
+    backend::sign(share, nonces, commitments, message)
+}
+
+/// Coordinator: combine the signature shares into a single ordinary [`Signature`] over `message`.
+///
+/// `public_key_package` must be the one [`keygen`] produced for this committee (any participant's
+/// [`KeyShare::public_key_package`] carries an identical copy) — each share is checked against its
+/// own verifying share from it before being combined, which is what actually rejects a bad share
+/// instead of silently accepting it.
+///
+/// # Errors
+/// Fails if the shares don't match `commitments`, a share doesn't verify against its signer's own
+/// share in `public_key_package`, or the combined signature fails to verify under the group key.
+pub fn aggregate(
+    public_key_package: &PublicKeyPackage,
+    commitments: &[SigningCommitment],
+    shares: &[SignatureShare],
+    message: &[u8],
+) -> Result<Signature, Error> {
+    let signature = backend::aggregate(&public_key_package.0, commitments, shares, message)?;
+    // Only ever emit a signature that already verifies through the existing single-key path.
+    signature.verify(message)?;
+    Ok(signature)
+}
+
+/// Thin adapters over `frost-ed25519` / `frost-secp256k1`, translating to and from iroha's
+/// [`KeyPair`](crate::KeyPair)/[`PublicKey`]/[`Signature`] types so verifiers stay unchanged.
+mod backend;
+
+// NOTE: `frost-ed25519`/`frost-secp256k1` aren't declared in any `Cargo.toml` in this tree (there
+// is none to edit but `fuzz/Cargo.toml`), so this module — and the tests below — can't actually
+// compile here. Written as if the dependencies were declared, so the round-trip and
+// rogue-participant coverage exists the moment that gap is closed.
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn run_round(algorithm: Algorithm, threshold: u16, max_signers: u16, message: &[u8]) -> Signature {
+        let shares = keygen(algorithm, threshold, max_signers, &mut OsRng).unwrap();
+        let public_key_package = shares.values().next().unwrap().public_key_package().clone();
+
+        let signing_set = shares.values().take(threshold as usize).collect::<Vec<_>>();
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signing_set
+            .iter()
+            .map(|share| commit(share, &mut OsRng))
+            .unzip();
+
+        let shares = signing_set
+            .iter()
+            .zip(nonces)
+            .map(|(share, nonces)| sign(share, nonces, &commitments, message).unwrap())
+            .collect::<Vec<_>>();
+
+        aggregate(&public_key_package, &commitments, &shares, message).unwrap()
+    }
+
+    #[test]
+    fn frost_ed25519_two_of_three_signs_and_verifies() {
+        let message = b"Test message to sign.";
+        let signature = run_round(Algorithm::Ed25519, 2, 3, message);
+        signature.verify(message).unwrap();
+    }
+
+    #[test]
+    fn frost_secp256k1_two_of_three_signs_and_verifies() {
+        let message = b"Test message to sign.";
+        let signature = run_round(Algorithm::Secp256k1, 2, 3, message);
+        signature.verify(message).unwrap();
+    }
+
+    #[test]
+    fn frost_signature_does_not_verify_under_an_unrelated_group_key() {
+        let message = b"Test message to sign.";
+        let signature = run_round(Algorithm::Ed25519, 2, 3, message);
+
+        let other_shares = keygen(Algorithm::Ed25519, 2, 3, &mut OsRng).unwrap();
+        let other_group_key = other_shares.values().next().unwrap().group_public_key();
+
+        // The signature was produced under a different committee's group key entirely, so it must
+        // not verify under this unrelated one.
+        let rebound = Signature::from_bytes(other_group_key.clone(), signature.payload());
+        assert!(rebound.verify(message).is_err());
+    }
+
+    #[test]
+    fn frost_sign_rejects_a_commitment_set_missing_the_signer() {
+        let shares = keygen(Algorithm::Ed25519, 2, 3, &mut OsRng).unwrap();
+        let mut signers = shares.values();
+        let signer = signers.next().unwrap();
+        let absent_signer = signers.next().unwrap();
+
+        let (nonces, _own_commitment) = commit(signer, &mut OsRng);
+        let (_, other_commitment) = commit(absent_signer, &mut OsRng);
+
+        // `commitments` never includes `signer`'s own commitment, so round 2 must reject it rather
+        // than silently sign with nonces that were never actually broadcast.
+        assert!(sign(signer, nonces, &[other_commitment], b"message").is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Curve dispatch for the [`frost`](super) module, wrapping `frost-ed25519` and `frost-secp256k1`.
+//!
+//! Each operation matches on the committee's [`FrostAlgorithm`] and forwards to the corresponding
+//! `frost-core` ciphersuite, converting identifiers and serialized material to and from iroha's
+//! crypto types. The curve-generic bodies live in the [`suite`] helper so the two arms stay in
+//! lockstep.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::CryptoRngCore;
+
+use super::{
+    FrostAlgorithm, KeyShare, ParticipantId, SignatureShare, SigningCommitment, SigningNonces,
+};
+use crate::{Error, Signature};
+
+/// Opaque per-participant secret package. Zeroizes on drop (enforced by `frost-core`).
+pub(super) enum KeyPackage {
+    Ed25519(frost_ed25519::keys::KeyPackage),
+    Secp256k1(frost_secp256k1::keys::KeyPackage),
+}
+
+/// Opaque public key package: the group verification key plus every participant's own verifying
+/// share, exactly as `frost-core` produced it during key generation.
+#[derive(Clone)]
+pub(super) enum PublicKeyPackage {
+    Ed25519(frost_ed25519::keys::PublicKeyPackage),
+    Secp256k1(frost_secp256k1::keys::PublicKeyPackage),
+}
+
+/// Opaque round-1 nonce pair.
+pub(super) enum SigningNoncesInner {
+    Ed25519(frost_ed25519::round1::SigningNonces),
+    Secp256k1(frost_secp256k1::round1::SigningNonces),
+}
+pub(super) use SigningNoncesInner as SigningNoncesRepr;
+pub(super) type SigningNonces = SigningNoncesInner;
+
+/// Opaque round-1 public commitment.
+#[derive(Clone)]
+pub(super) enum SigningCommitments {
+    Ed25519(frost_ed25519::round1::SigningCommitments),
+    Secp256k1(frost_secp256k1::round1::SigningCommitments),
+}
+
+/// Opaque round-2 signature share.
+#[derive(Clone)]
+pub(super) enum SignatureShare {
+    Ed25519(frost_ed25519::round2::SignatureShare),
+    Secp256k1(frost_secp256k1::round2::SignatureShare),
+}
+
+pub(super) fn keygen(
+    algorithm: FrostAlgorithm,
+    threshold: u16,
+    max_signers: u16,
+    rng: &mut impl CryptoRngCore,
+) -> Result<BTreeMap<ParticipantId, KeyShare>, Error> {
+    match algorithm {
+        FrostAlgorithm::Ed25519 => suite::ed25519::keygen(threshold, max_signers, rng),
+        FrostAlgorithm::Secp256k1 => suite::secp256k1::keygen(threshold, max_signers, rng),
+    }
+}
+
+pub(super) fn commit(
+    share: &KeyShare,
+    rng: &mut impl CryptoRngCore,
+) -> (super::SigningNonces, SigningCommitment) {
+    match &share.package {
+        KeyPackage::Ed25519(pkg) => suite::ed25519::commit(share.id, pkg, rng),
+        KeyPackage::Secp256k1(pkg) => suite::secp256k1::commit(share.id, pkg, rng),
+    }
+}
+
+pub(super) fn sign(
+    share: &KeyShare,
+    nonces: super::SigningNonces,
+    commitments: &[SigningCommitment],
+    message: &[u8],
+) -> Result<super::SignatureShare, Error> {
+    match (&share.package, nonces.0) {
+        (KeyPackage::Ed25519(pkg), SigningNoncesInner::Ed25519(nonces)) => {
+            suite::ed25519::sign(share.id, pkg, nonces, commitments, message)
+        }
+        (KeyPackage::Secp256k1(pkg), SigningNoncesInner::Secp256k1(nonces)) => {
+            suite::secp256k1::sign(share.id, pkg, nonces, commitments, message)
+        }
+        _ => Err(Error::Other("FROST nonces/key-share curve mismatch".into())),
+    }
+}
+
+pub(super) fn aggregate(
+    public_key_package: &PublicKeyPackage,
+    commitments: &[SigningCommitment],
+    shares: &[SignatureShare],
+    message: &[u8],
+) -> Result<Signature, Error> {
+    match public_key_package {
+        PublicKeyPackage::Ed25519(pubkeys) => {
+            suite::ed25519::aggregate(pubkeys, commitments, shares, message)
+        }
+        PublicKeyPackage::Secp256k1(pubkeys) => {
+            suite::secp256k1::aggregate(pubkeys, commitments, shares, message)
+        }
+    }
+}
+
+/// Generates one `suite::$curve` module per ciphersuite. The bodies are identical modulo the
+/// `frost-*` crate in scope, so the macro keeps Ed25519 and secp256k1 byte-for-byte in lockstep.
+macro_rules! frost_suite {
+    ($module:ident, $crate_path:path, $variant:ident, $public_key:expr) => {
+        pub(super) mod $module {
+            use alloc::{collections::BTreeMap, vec::Vec};
+
+            use rand_core::CryptoRngCore;
+            use $crate_path as frost;
+
+            use super::super::{
+                KeyPackage, KeyShare, ParticipantId, SignatureShare as OuterShare,
+                SigningCommitment, SigningCommitments, SigningNonces as OuterNonces,
+                SigningNoncesInner,
+            };
+            use crate::{Error, PublicKey, Signature};
+
+            fn participant_id(id: ParticipantId) -> Result<frost::Identifier, Error> {
+                frost::Identifier::try_from(id.get())
+                    .map_err(|e| Error::Other(alloc::format!("invalid FROST id: {e}").into()))
+            }
+
+            fn group_public_key(
+                pubkeys: &frost::keys::PublicKeyPackage,
+            ) -> Result<PublicKey, Error> {
+                let bytes = pubkeys
+                    .verifying_key()
+                    .serialize()
+                    .map_err(map_err)?;
+                $public_key(&bytes)
+            }
+
+            fn map_err(e: impl core::fmt::Display) -> Error {
+                Error::Other(alloc::format!("FROST {}: {e}", stringify!($module)).into())
+            }
+
+            pub(in super::super) fn keygen(
+                threshold: u16,
+                max_signers: u16,
+                rng: &mut impl CryptoRngCore,
+            ) -> Result<BTreeMap<ParticipantId, KeyShare>, Error> {
+                let (shares, pubkeys) = frost::keys::generate_with_dealer(
+                    max_signers,
+                    threshold,
+                    frost::keys::IdentifierList::Default,
+                    rng,
+                )
+                .map_err(map_err)?;
+                let group_public_key = group_public_key(&pubkeys)?;
+                // Carried by every `KeyShare` so the coordinator can check each signature share
+                // against its own verifying share, not the group key, when aggregating.
+                let public_key_package =
+                    super::super::PublicKeyPackage(super::PublicKeyPackage::$variant(pubkeys));
+
+                let mut out = BTreeMap::new();
+                for (identifier, secret_share) in shares {
+                    let package = frost::keys::KeyPackage::try_from(secret_share).map_err(map_err)?;
+                    let id = identifier_to_participant(&identifier, max_signers)?;
+                    out.insert(
+                        id,
+                        KeyShare {
+                            algorithm: super::super::FrostAlgorithm::$variant,
+                            id,
+                            package: KeyPackage::$variant(package),
+                            group_public_key: group_public_key.clone(),
+                            public_key_package: public_key_package.clone(),
+                        },
+                    );
+                }
+                Ok(out)
+            }
+
+            fn identifier_to_participant(
+                identifier: &frost::Identifier,
+                max_signers: u16,
+            ) -> Result<ParticipantId, Error> {
+                // `IdentifierList::Default` assigns `1..=n`, so the id is the `n` whose identifier
+                // matches. Compared as identifiers because the serialized scalar's byte order
+                // differs between curves (little-endian for Ed25519, big-endian for secp256k1).
+                (1..=max_signers)
+                    .find(|&raw| frost::Identifier::try_from(raw).ok().as_ref() == Some(identifier))
+                    .and_then(ParticipantId::new)
+                    .ok_or_else(|| Error::Other("FROST identifier out of range".into()))
+            }
+
+            pub(in super::super) fn commit(
+                id: ParticipantId,
+                package: &frost::keys::KeyPackage,
+                rng: &mut impl CryptoRngCore,
+            ) -> (OuterNonces, SigningCommitment) {
+                let (nonces, commitments) = frost::round1::commit(package.signing_share(), rng);
+                (
+                    OuterNonces(SigningNoncesInner::$variant(nonces)),
+                    SigningCommitment {
+                        id,
+                        inner: SigningCommitments::$variant(commitments),
+                    },
+                )
+            }
+
+            pub(in super::super) fn sign(
+                id: ParticipantId,
+                package: &frost::keys::KeyPackage,
+                nonces: frost::round1::SigningNonces,
+                commitments: &[SigningCommitment],
+                message: &[u8],
+            ) -> Result<OuterShare, Error> {
+                let signing_package = signing_package(commitments, message)?;
+                let share = frost::round2::sign(&signing_package, &nonces, package).map_err(map_err)?;
+                Ok(OuterShare {
+                    id,
+                    inner: super::super::SignatureShare::$variant(share),
+                })
+            }
+
+            fn signing_package(
+                commitments: &[SigningCommitment],
+                message: &[u8],
+            ) -> Result<frost::SigningPackage, Error> {
+                let mut map = BTreeMap::new();
+                for commitment in commitments {
+                    let SigningCommitments::$variant(inner) = &commitment.inner else {
+                        return Err(Error::Other("FROST commitment curve mismatch".into()));
+                    };
+                    map.insert(participant_id(commitment.id)?, inner.clone());
+                }
+                Ok(frost::SigningPackage::new(map, message))
+            }
+
+            pub(in super::super) fn aggregate(
+                pubkeys: &frost::keys::PublicKeyPackage,
+                commitments: &[SigningCommitment],
+                shares: &[OuterShare],
+                message: &[u8],
+            ) -> Result<Signature, Error> {
+                let signing_package = signing_package(commitments, message)?;
+                let mut share_map = BTreeMap::new();
+                for share in shares {
+                    let super::super::SignatureShare::$variant(inner) = &share.inner else {
+                        return Err(Error::Other("FROST share curve mismatch".into()));
+                    };
+                    share_map.insert(participant_id(share.id)?, inner.clone());
+                }
+                // `pubkeys` carries every signer's own verifying share, so each one is checked
+                // against its actual signer here rather than against the group key.
+                let signature =
+                    frost::aggregate(&signing_package, &share_map, pubkeys).map_err(map_err)?;
+                let bytes = signature.serialize().map_err(map_err)?;
+                let group_public_key = group_public_key(pubkeys)?;
+                Ok(Signature::from_bytes(group_public_key, &bytes))
+            }
+        }
+    };
+}
+
+mod suite {
+    frost_suite!(
+        ed25519,
+        frost_ed25519,
+        Ed25519,
+        |bytes: &[u8]| crate::PublicKey::from_bytes(crate::Algorithm::Ed25519, bytes)
+    );
+    frost_suite!(
+        secp256k1,
+        frost_secp256k1,
+        Secp256k1,
+        |bytes: &[u8]| crate::PublicKey::from_bytes(crate::Algorithm::Secp256k1, bytes)
+    );
+}
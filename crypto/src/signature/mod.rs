@@ -4,9 +4,20 @@
 #[cfg(not(feature = "ffi_import"))]
 pub(crate) mod bls;
 
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+pub(crate) mod bls_aggregate;
+
 #[cfg(not(feature = "ffi_import"))]
 pub(crate) mod ed25519;
 
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+pub(crate) mod ed25519_batch;
+
+#[cfg(all(feature = "std", not(feature = "ffi_import")))]
+pub(crate) mod frost;
+
 #[cfg(not(feature = "ffi_import"))]
 pub(crate) mod secp256k1;
 
@@ -158,11 +169,11 @@ ffi::ffi_item! {
     #[cfg_attr(not(feature="ffi_import"), serde(transparent))]
     // Transmute guard
     #[repr(transparent)]
-    pub struct SignatureOf<T>(
+    pub struct SignatureOf<T, Ctx = DefaultContext>(
         #[deref]
         #[deref_mut]
         Signature,
-        #[cfg_attr(not(feature = "ffi_import"), codec(skip))] PhantomData<T>,
+        #[cfg_attr(not(feature = "ffi_import"), codec(skip))] PhantomData<(T, Ctx)>,
     );
 
     // SAFETY: `SignatureOf` has no trap representation in `Signature`
@@ -170,7 +181,7 @@ ffi::ffi_item! {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::fmt::Debug for SignatureOf<T> {
+impl<T, Ctx> core::fmt::Debug for SignatureOf<T, Ctx> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple(core::any::type_name::<Self>())
             .field(&self.0)
@@ -178,40 +189,40 @@ impl<T> core::fmt::Debug for SignatureOf<T> {
     }
 }
 
-impl<T> Clone for SignatureOf<T> {
+impl<T, Ctx> Clone for SignatureOf<T, Ctx> {
     fn clone(&self) -> Self {
         Self(self.0.clone(), PhantomData)
     }
 }
 
 #[allow(clippy::unconditional_recursion)] // False-positive
-impl<T> PartialEq for SignatureOf<T> {
+impl<T, Ctx> PartialEq for SignatureOf<T, Ctx> {
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
     }
 }
-impl<T> Eq for SignatureOf<T> {}
+impl<T, Ctx> Eq for SignatureOf<T, Ctx> {}
 
-impl<T> PartialOrd for SignatureOf<T> {
+impl<T, Ctx> PartialOrd for SignatureOf<T, Ctx> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
-impl<T> Ord for SignatureOf<T> {
+impl<T, Ctx> Ord for SignatureOf<T, Ctx> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.0.cmp(&other.0)
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::hash::Hash for SignatureOf<T> {
+impl<T, Ctx> core::hash::Hash for SignatureOf<T, Ctx> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T: IntoSchema> IntoSchema for SignatureOf<T> {
+impl<T: IntoSchema, Ctx> IntoSchema for SignatureOf<T, Ctx> {
     fn type_name() -> String {
         format!("SignatureOf<{}>", T::type_name())
     }
@@ -228,27 +239,64 @@ impl<T: IntoSchema> IntoSchema for SignatureOf<T> {
     }
 }
 
-impl<T> SignatureOf<T> {
+/// Domain-separation context for a signed message type.
+///
+/// The associated [`DOMAIN_TAG`](SigningContext::DOMAIN_TAG) is prepended to the hash before
+/// signing and verification, so a signature produced under one context can never be replayed as a
+/// valid signature under another — the separation is bound cryptographically, not merely tracked in
+/// a [`PhantomData`] marker. This mirrors how RedDSA distinguishes `SpendAuth` from `Binding`
+/// signatures in the type system.
+///
+/// `Ctx` is a second, defaulted type parameter on [`SignatureOf`]/[`SignaturesOf`] rather than a
+/// bound on the signed type `T` itself: binding it to `T` would require every already-signed type
+/// (`Transaction`, queries, ...) to grow an impl of this trait just to keep calling
+/// [`SignatureOf::new`]/[`verify`](SignatureOf::verify), breaking them from outside this crate. A
+/// fresh, crate-local marker type implementing this trait with a non-empty tag opts a signed value
+/// into domain separation by spelling out that marker as `Ctx`; every existing call site keeps using
+/// the defaulted [`DefaultContext`] and is unaffected.
+pub trait SigningContext {
+    /// Domain-separation tag bound into every signature using this context (e.g. `b"iroha/block/v1"`).
+    const DOMAIN_TAG: &'static [u8] = b"";
+}
+
+/// The context used when `Ctx` is left at its default: the empty tag, so `SignatureOf<T>` /
+/// `SignaturesOf<T>` keep verifying exactly the signatures they always have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultContext;
+
+impl SigningContext for DefaultContext {}
+
+impl<T, Ctx: SigningContext> SignatureOf<T, Ctx> {
     /// Create [`SignatureOf`] from the given hash with [`KeyPair::private_key`].
     ///
     /// # Errors
     /// Fails if signing fails
     #[inline]
     fn from_hash(key_pair: &KeyPair, hash: HashOf<T>) -> Self {
-        Self(Signature::new(key_pair, hash.as_ref()), PhantomData)
+        Self(Signature::new(key_pair, &domain_separated(Ctx::DOMAIN_TAG, hash)), PhantomData)
     }
 
     /// Verify signature for this hash
     ///
     /// # Errors
     ///
-    /// Fails if the given hash didn't pass verification
+    /// Fails if the given hash didn't pass verification. A signature created under a different
+    /// [`SigningContext`] fails here because its tag doesn't match, surfacing a replay attempt as
+    /// an ordinary verification failure.
     fn verify_hash(&self, hash: HashOf<T>) -> Result<(), Error> {
-        self.0.verify(hash.as_ref())
+        self.0.verify(&domain_separated(Ctx::DOMAIN_TAG, hash))
     }
 }
 
-impl<T: parity_scale_codec::Encode> SignatureOf<T> {
+/// Prepend the domain-separation `tag` to `hash` to obtain the bytes actually signed/verified.
+fn domain_separated<T>(tag: &[u8], hash: HashOf<T>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(tag.len() + hash.as_ref().len());
+    bytes.extend_from_slice(tag);
+    bytes.extend_from_slice(hash.as_ref());
+    bytes
+}
+
+impl<T: parity_scale_codec::Encode, Ctx: SigningContext> SignatureOf<T, Ctx> {
     /// Create [`SignatureOf`] by signing the given value with [`KeyPair::private_key`].
     /// The value provided will be hashed before being signed. If you already have the
     /// hash of the value you can sign it with [`SignatureOf::from_hash`] instead.
@@ -276,29 +324,29 @@ impl<T: parity_scale_codec::Encode> SignatureOf<T> {
 #[schema(transparent)]
 #[repr(transparent)]
 #[cfg(not(feature = "ffi_import"))]
-pub struct SignatureWrapperOf<T>(
+pub struct SignatureWrapperOf<T, Ctx = DefaultContext>(
     #[deref]
     #[deref_mut]
-    SignatureOf<T>,
+    SignatureOf<T, Ctx>,
 );
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> SignatureWrapperOf<T> {
+impl<T, Ctx> SignatureWrapperOf<T, Ctx> {
     #[inline]
-    fn inner(self) -> SignatureOf<T> {
+    fn inner(self) -> SignatureOf<T, Ctx> {
         self.0
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::fmt::Debug for SignatureWrapperOf<T> {
+impl<T, Ctx> core::fmt::Debug for SignatureWrapperOf<T, Ctx> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Clone for SignatureWrapperOf<T> {
+impl<T, Ctx> Clone for SignatureWrapperOf<T, Ctx> {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
@@ -306,29 +354,29 @@ impl<T> Clone for SignatureWrapperOf<T> {
 
 #[allow(clippy::unconditional_recursion)] // False-positive
 #[cfg(not(feature = "ffi_import"))]
-impl<T> PartialEq for SignatureWrapperOf<T> {
+impl<T, Ctx> PartialEq for SignatureWrapperOf<T, Ctx> {
     fn eq(&self, other: &Self) -> bool {
         self.0.public_key().eq(other.0.public_key())
     }
 }
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Eq for SignatureWrapperOf<T> {}
+impl<T, Ctx> Eq for SignatureWrapperOf<T, Ctx> {}
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> PartialOrd for SignatureWrapperOf<T> {
+impl<T, Ctx> PartialOrd for SignatureWrapperOf<T, Ctx> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Ord for SignatureWrapperOf<T> {
+impl<T, Ctx> Ord for SignatureWrapperOf<T, Ctx> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.0.public_key().cmp(other.0.public_key())
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::hash::Hash for SignatureWrapperOf<T> {
+impl<T, Ctx> core::hash::Hash for SignatureWrapperOf<T, Ctx> {
     // Implement `Hash` manually to be consistent with `Ord`
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.public_key().hash(state);
@@ -347,12 +395,12 @@ impl<T> core::hash::Hash for SignatureWrapperOf<T> {
 // Transmute guard
 #[repr(transparent)]
 #[cfg(not(feature = "ffi_import"))]
-pub struct SignaturesOf<T> {
-    signatures: btree_set::BTreeSet<SignatureWrapperOf<T>>,
+pub struct SignaturesOf<T, Ctx = DefaultContext> {
+    signatures: btree_set::BTreeSet<SignatureWrapperOf<T, Ctx>>,
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::fmt::Debug for SignaturesOf<T> {
+impl<T, Ctx> core::fmt::Debug for SignaturesOf<T, Ctx> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct(core::any::type_name::<Self>())
             .field("signatures", &self.signatures)
@@ -361,7 +409,7 @@ impl<T> core::fmt::Debug for SignaturesOf<T> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Clone for SignaturesOf<T> {
+impl<T, Ctx> Clone for SignaturesOf<T, Ctx> {
     fn clone(&self) -> Self {
         let signatures = self.signatures.clone();
         Self { signatures }
@@ -370,35 +418,35 @@ impl<T> Clone for SignaturesOf<T> {
 
 #[allow(clippy::unconditional_recursion)] // False-positive
 #[cfg(not(feature = "ffi_import"))]
-impl<T> PartialEq for SignaturesOf<T> {
+impl<T, Ctx> PartialEq for SignaturesOf<T, Ctx> {
     fn eq(&self, other: &Self) -> bool {
         self.signatures.eq(&other.signatures)
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Eq for SignaturesOf<T> {}
+impl<T, Ctx> Eq for SignaturesOf<T, Ctx> {}
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> PartialOrd for SignaturesOf<T> {
+impl<T, Ctx> PartialOrd for SignaturesOf<T, Ctx> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> Ord for SignaturesOf<T> {
+impl<T, Ctx> Ord for SignaturesOf<T, Ctx> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.signatures.cmp(&other.signatures)
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> IntoIterator for SignaturesOf<T> {
-    type Item = SignatureOf<T>;
+impl<T, Ctx> IntoIterator for SignaturesOf<T, Ctx> {
+    type Item = SignatureOf<T, Ctx>;
     type IntoIter = core::iter::Map<
-        btree_set::IntoIter<SignatureWrapperOf<T>>,
-        fn(SignatureWrapperOf<T>) -> SignatureOf<T>,
+        btree_set::IntoIter<SignatureWrapperOf<T, Ctx>>,
+        fn(SignatureWrapperOf<T, Ctx>) -> SignatureOf<T, Ctx>,
     >;
     fn into_iter(self) -> Self::IntoIter {
         self.signatures.into_iter().map(SignatureWrapperOf::inner)
@@ -406,11 +454,11 @@ impl<T> IntoIterator for SignaturesOf<T> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<'itm, T> IntoIterator for &'itm SignaturesOf<T> {
-    type Item = &'itm SignatureOf<T>;
+impl<'itm, T, Ctx> IntoIterator for &'itm SignaturesOf<T, Ctx> {
+    type Item = &'itm SignatureOf<T, Ctx>;
     type IntoIter = core::iter::Map<
-        btree_set::Iter<'itm, SignatureWrapperOf<T>>,
-        fn(&'itm SignatureWrapperOf<T>) -> &'itm SignatureOf<T>,
+        btree_set::Iter<'itm, SignatureWrapperOf<T, Ctx>>,
+        fn(&'itm SignatureWrapperOf<T, Ctx>) -> &'itm SignatureOf<T, Ctx>,
     >;
     fn into_iter(self) -> Self::IntoIter {
         self.signatures.iter().map(core::ops::Deref::deref)
@@ -418,10 +466,10 @@ impl<'itm, T> IntoIterator for &'itm SignaturesOf<T> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<A> Extend<SignatureOf<A>> for SignaturesOf<A> {
+impl<A, Ctx> Extend<SignatureOf<A, Ctx>> for SignaturesOf<A, Ctx> {
     fn extend<T>(&mut self, iter: T)
     where
-        T: IntoIterator<Item = SignatureOf<A>>,
+        T: IntoIterator<Item = SignatureOf<A, Ctx>>,
     {
         for signature in iter {
             self.insert(signature);
@@ -430,22 +478,22 @@ impl<A> Extend<SignatureOf<A>> for SignaturesOf<A> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> From<SignaturesOf<T>> for btree_set::BTreeSet<SignatureOf<T>> {
-    fn from(source: SignaturesOf<T>) -> Self {
+impl<T, Ctx> From<SignaturesOf<T, Ctx>> for btree_set::BTreeSet<SignatureOf<T, Ctx>> {
+    fn from(source: SignaturesOf<T, Ctx>) -> Self {
         source.into_iter().collect()
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> From<btree_set::BTreeSet<SignatureOf<T>>> for SignaturesOf<T> {
-    fn from(source: btree_set::BTreeSet<SignatureOf<T>>) -> Self {
+impl<T, Ctx> From<btree_set::BTreeSet<SignatureOf<T, Ctx>>> for SignaturesOf<T, Ctx> {
+    fn from(source: btree_set::BTreeSet<SignatureOf<T, Ctx>>) -> Self {
         source.into_iter().collect()
     }
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<A> From<SignatureOf<A>> for SignaturesOf<A> {
-    fn from(signature: SignatureOf<A>) -> Self {
+impl<A, Ctx> From<SignatureOf<A, Ctx>> for SignaturesOf<A, Ctx> {
+    fn from(signature: SignatureOf<A, Ctx>) -> Self {
         Self {
             signatures: [SignatureWrapperOf(signature)].into(),
         }
@@ -453,8 +501,8 @@ impl<A> From<SignatureOf<A>> for SignaturesOf<A> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<A> FromIterator<SignatureOf<A>> for SignaturesOf<A> {
-    fn from_iter<T: IntoIterator<Item = SignatureOf<A>>>(signatures: T) -> Self {
+impl<A, Ctx> FromIterator<SignatureOf<A, Ctx>> for SignaturesOf<A, Ctx> {
+    fn from_iter<T: IntoIterator<Item = SignatureOf<A, Ctx>>>(signatures: T) -> Self {
         Self {
             signatures: signatures.into_iter().map(SignatureWrapperOf).collect(),
         }
@@ -462,15 +510,15 @@ impl<A> FromIterator<SignatureOf<A>> for SignaturesOf<A> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> SignaturesOf<T> {
+impl<T, Ctx> SignaturesOf<T, Ctx> {
     /// Adds a signature. If the signature with this key was present, replaces it.
-    pub fn insert(&mut self, signature: SignatureOf<T>) {
+    pub fn insert(&mut self, signature: SignatureOf<T, Ctx>) {
         self.signatures.insert(SignatureWrapperOf(signature));
     }
 
     /// Return all signatures.
     #[inline]
-    pub fn iter(&self) -> impl ExactSizeIterator<Item = &SignatureOf<T>> {
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &SignatureOf<T, Ctx>> {
         self.into_iter()
     }
 
@@ -485,7 +533,10 @@ impl<T> SignaturesOf<T> {
     ///
     /// # Errors
     /// Fails if verificatoin of any signature fails
-    pub fn verify_hash(&self, hash: HashOf<T>) -> Result<(), SignatureVerificationFail<T>> {
+    pub fn verify_hash(&self, hash: HashOf<T>) -> Result<(), SignatureVerificationFail<T, Ctx>>
+    where
+        Ctx: SigningContext,
+    {
         self.iter().try_for_each(|signature| {
             signature
                 .verify_hash(hash)
@@ -502,8 +553,112 @@ impl<T> SignaturesOf<T> {
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> SignaturesOf<T, Ctx> {
+    /// Combine the contained signatures into a single BLS [`AggregateSignature`].
+    ///
+    /// All signatures must be made with the same BLS algorithm; verifying the aggregate then
+    /// costs one multi-pairing check instead of one pairing per signer.
+    ///
+    /// # Errors
+    /// Fails if the set is empty, mixes algorithms, or contains a non-BLS signature.
+    pub fn aggregate(&self) -> Result<AggregateSignature<T, Ctx>, Error> {
+        let mut algorithm = None;
+        for signature in self {
+            let signer = signature.public_key().algorithm();
+            if !matches!(signer, crate::Algorithm::BlsSmall | crate::Algorithm::BlsNormal) {
+                return Err(Error::UnsupportedAlgorithm(signer.to_string()));
+            }
+            match algorithm {
+                None => algorithm = Some(signer),
+                Some(existing) if existing != signer => {
+                    return Err(Error::Other("cannot aggregate mixed signature algorithms".into()))
+                }
+                Some(_) => {}
+            }
+        }
+        let algorithm = algorithm
+            .ok_or_else(|| Error::Other("cannot aggregate an empty signature set".into()))?;
+
+        let payloads = self.iter().map(SignatureOf::payload).collect::<Vec<_>>();
+        let signature = bls_aggregate::aggregate_signatures(algorithm, &payloads)?;
+        Ok(AggregateSignature {
+            algorithm,
+            signature: ConstVec::new(signature),
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A BLS aggregate signature over a single hash, produced by [`SignaturesOf::aggregate`].
+///
+/// Verifiable against the set of signer public keys in one multi-pairing check via
+/// [`AggregateSignature::verify_aggregate`].
+#[cfg(not(feature = "ffi_import"))]
+#[derive(Clone, PartialEq, Eq, Decode, Encode, Serialize, Deserialize)]
+pub struct AggregateSignature<T, Ctx = DefaultContext> {
+    algorithm: crate::Algorithm,
+    signature: ConstVec<u8>,
+    #[codec(skip)]
+    #[serde(skip)]
+    _marker: PhantomData<(T, Ctx)>,
+}
+
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx: SigningContext> AggregateSignature<T, Ctx> {
+    /// Verify the aggregate against `public_keys` over `hash` in a single multi-pairing check.
+    ///
+    /// # Errors
+    /// Fails if any key isn't a BLS key of the aggregate's algorithm, or the check doesn't pass.
+    pub fn verify_aggregate(
+        &self,
+        public_keys: &[PublicKey],
+        hash: HashOf<T>,
+    ) -> Result<(), Error> {
+        for public_key in public_keys {
+            if public_key.algorithm() != self.algorithm {
+                return Err(Error::UnsupportedAlgorithm(public_key.algorithm().to_string()));
+            }
+        }
+        let message = domain_separated(Ctx::DOMAIN_TAG, hash);
+        bls_aggregate::verify_aggregate(self.algorithm, &self.signature, public_keys, &message)
+    }
+
+    /// Fold an additional individual `signature` into this aggregate.
+    ///
+    /// # Errors
+    /// Fails if `signature` isn't a BLS signature of this aggregate's algorithm.
+    pub fn aggregate_with(&self, signature: &SignatureOf<T, Ctx>) -> Result<Self, Error> {
+        let signer_algorithm = signature.public_key().algorithm();
+        if signer_algorithm != self.algorithm {
+            return Err(Error::UnsupportedAlgorithm(signer_algorithm.to_string()));
+        }
+        let combined = bls_aggregate::aggregate_signatures(
+            self.algorithm,
+            &[self.signature.as_ref(), signature.payload()],
+        )?;
+        Ok(Self {
+            algorithm: self.algorithm,
+            signature: ConstVec::new(combined),
+            _marker: PhantomData,
+        })
+    }
+}
+
 #[cfg(not(feature = "ffi_import"))]
-impl<T: Encode> SignaturesOf<T> {
+impl<T, Ctx> core::fmt::Debug for AggregateSignature<T, Ctx> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AggregateSignature")
+            .field("algorithm", &self.algorithm)
+            .field("signature", &hex::encode_upper(self.signature.as_ref()))
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl<T: Encode, Ctx: SigningContext> SignaturesOf<T, Ctx> {
     /// Create new signatures container
     ///
     /// # Errors
@@ -517,22 +672,222 @@ impl<T: Encode> SignaturesOf<T> {
     ///
     /// # Errors
     /// Fails if validation of any signature fails
-    pub fn verify(&self, item: &T) -> Result<(), SignatureVerificationFail<T>> {
+    pub fn verify(&self, item: &T) -> Result<(), SignatureVerificationFail<T, Ctx>> {
         self.verify_hash(HashOf::new(item))
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> SignaturesOf<T, Ctx> {
+    /// Verify all contained signatures against `hash`, batching the Ed25519 subset into a single
+    /// combined check.
+    ///
+    /// Each Ed25519 signature is weighted by a random 128-bit scalar `z_i` and the aggregate
+    /// equation `(Σ z_i·s_i mod ℓ)·B = Σ z_i·R_i + Σ (z_i·H(R_i‖A_i‖M))·A_i` is verified with a
+    /// single multi-scalar multiplication. The random coefficients are mandatory: without them an
+    /// attacker could craft signatures that cancel in the sum. Non-batchable algorithms fall back
+    /// to [`verify_hash`](Self::verify_hash) per signature.
+    ///
+    /// On success returns `Ok(())`. On failure the individual, exact path is re-run to pin down and
+    /// report the offending [`SignatureVerificationFail`].
+    pub fn verify_hash_batch(&self, hash: HashOf<T>) -> Result<(), SignatureVerificationFail<T, Ctx>>
+    where
+        Ctx: SigningContext,
+    {
+        let mut ed25519 = Vec::new();
+
+        for signature in self {
+            if signature.public_key().algorithm() == crate::Algorithm::Ed25519 {
+                ed25519.push(signature);
+            } else {
+                // Not batchable — verify exactly.
+                self.verify_one(signature, hash)?;
+            }
+        }
+
+        if !ed25519.is_empty() {
+            let batch = ed25519
+                .iter()
+                .map(|signature| (signature.public_key(), signature.payload()))
+                .collect::<Vec<_>>();
+
+            // Each signature was made over the domain-separated bytes, not the bare hash.
+            let message = domain_separated(Ctx::DOMAIN_TAG, hash);
+            if ed25519_batch::verify_batch(&message, &batch).is_err() {
+                // The batch equation rejected — fall back to report the specific culprit.
+                for signature in ed25519 {
+                    self.verify_one(signature, hash)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify a single signature against `hash`, wrapping the error as a [`SignatureVerificationFail`].
+    fn verify_one(
+        &self,
+        signature: &SignatureOf<T, Ctx>,
+        hash: HashOf<T>,
+    ) -> Result<(), SignatureVerificationFail<T, Ctx>>
+    where
+        Ctx: SigningContext,
+    {
+        signature
+            .verify_hash(hash)
+            .map_err(|error| SignatureVerificationFail {
+                signature: Box::new(signature.clone()),
+                reason: error.to_string(),
+            })
+    }
+}
+
+/// An `m-of-n` verification policy: a set of authorized signer keys and the number of them that
+/// must produce a valid signature, in the spirit of TUF's role metadata thresholds.
+#[cfg(not(feature = "ffi_import"))]
+#[derive(Debug, Clone)]
+pub struct VerificationPolicy {
+    authorized: btree_set::BTreeSet<PublicKey>,
+    threshold: core::num::NonZeroUsize,
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl VerificationPolicy {
+    /// Build a policy requiring `threshold` valid signatures from the `authorized` key set.
+    ///
+    /// # Errors
+    /// Fails if `threshold` exceeds the number of authorized keys — such a policy can never be met.
+    pub fn new(
+        authorized: impl IntoIterator<Item = PublicKey>,
+        threshold: core::num::NonZeroUsize,
+    ) -> Result<Self, Error> {
+        let authorized = authorized.into_iter().collect::<btree_set::BTreeSet<_>>();
+        if threshold.get() > authorized.len() {
+            return Err(Error::Other(
+                "verification threshold exceeds the authorized key set".into(),
+            ));
+        }
+        Ok(Self {
+            authorized,
+            threshold,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> SignaturesOf<T, Ctx> {
+    /// Verify this set against a [`VerificationPolicy`], i.e. an `m-of-n` role check.
+    ///
+    /// Counts the signatures that are both made by an authorized key and cryptographically valid.
+    /// Because a [`SignaturesOf`] holds at most one signature per key, each authorized key counts
+    /// at most once.
+    ///
+    /// # Errors
+    /// Fails with a [`ThresholdVerificationFail`] listing the authorized keys that were missing or
+    /// whose signature was invalid, when fewer than `threshold` valid signatures are present.
+    pub fn verify_with_policy(
+        &self,
+        hash: HashOf<T>,
+        policy: &VerificationPolicy,
+    ) -> Result<(), ThresholdVerificationFail<T, Ctx>>
+    where
+        Ctx: SigningContext,
+    {
+        let mut valid = 0_usize;
+        let mut invalid = Vec::new();
+        let mut signed = btree_set::BTreeSet::new();
+
+        for signature in self {
+            if !policy.authorized.contains(signature.public_key()) {
+                continue;
+            }
+            signed.insert(signature.public_key().clone());
+            match signature.verify_hash(hash) {
+                Ok(()) => valid += 1,
+                Err(error) => invalid.push(SignatureVerificationFail {
+                    signature: Box::new(signature.clone()),
+                    reason: error.to_string(),
+                }),
+            }
+        }
+
+        if valid >= policy.threshold.get() {
+            return Ok(());
+        }
+
+        let missing = policy
+            .authorized
+            .iter()
+            .filter(|key| !signed.contains(*key))
+            .cloned()
+            .collect();
+        Err(ThresholdVerificationFail {
+            required: policy.threshold.get(),
+            valid,
+            missing,
+            invalid,
+        })
+    }
+}
+
+/// Failure of a [`VerificationPolicy`] check: fewer than the required number of authorized keys
+/// produced a valid signature.
+#[cfg(not(feature = "ffi_import"))]
+#[derive(Clone)]
+pub struct ThresholdVerificationFail<T, Ctx = DefaultContext> {
+    /// Number of valid authorized signatures required.
+    pub required: usize,
+    /// Number of valid authorized signatures found.
+    pub valid: usize,
+    /// Authorized keys that did not sign at all.
+    pub missing: Vec<PublicKey>,
+    /// Authorized keys whose signature failed verification.
+    pub invalid: Vec<SignatureVerificationFail<T, Ctx>>,
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> core::fmt::Debug for ThresholdVerificationFail<T, Ctx> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThresholdVerificationFail")
+            .field("required", &self.required)
+            .field("valid", &self.valid)
+            .field("missing", &self.missing)
+            .field("invalid", &self.invalid)
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> core::fmt::Display for ThresholdVerificationFail<T, Ctx> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Threshold not met: {} of {} required authorized signatures valid ({} missing, {} invalid)",
+            self.valid,
+            self.required,
+            self.missing.len(),
+            self.invalid.len(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ffi_import"))]
+impl<T, Ctx> std::error::Error for ThresholdVerificationFail<T, Ctx> {}
+
 /// Verification failed of some signature due to following reason
 #[derive(Clone, PartialEq, Eq)]
-pub struct SignatureVerificationFail<T> {
+pub struct SignatureVerificationFail<T, Ctx = DefaultContext> {
     /// Signature which verification has failed
-    pub signature: Box<SignatureOf<T>>,
+    pub signature: Box<SignatureOf<T, Ctx>>,
     /// Error which happened during verification
     pub reason: String,
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::fmt::Debug for SignatureVerificationFail<T> {
+impl<T, Ctx> core::fmt::Debug for SignatureVerificationFail<T, Ctx> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SignatureVerificationFail")
             .field("signature", &self.signature.0)
@@ -542,7 +897,7 @@ impl<T> core::fmt::Debug for SignatureVerificationFail<T> {
 }
 
 #[cfg(not(feature = "ffi_import"))]
-impl<T> core::fmt::Display for SignatureVerificationFail<T> {
+impl<T, Ctx> core::fmt::Display for SignatureVerificationFail<T, Ctx> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
@@ -555,7 +910,7 @@ impl<T> core::fmt::Display for SignatureVerificationFail<T> {
 
 #[cfg(feature = "std")]
 #[cfg(not(feature = "ffi_import"))]
-impl<T> std::error::Error for SignatureVerificationFail<T> {}
+impl<T, Ctx> std::error::Error for SignatureVerificationFail<T, Ctx> {}
 
 #[cfg(test)]
 mod tests {
@@ -672,4 +1027,77 @@ mod tests {
         assert_eq!(value.public_key().to_string(), public_key);
         assert_eq!(value.payload(), hex::decode(payload).unwrap());
     }
+
+    // NOTE: the tests below exercise `SignaturesOf::aggregate`/`AggregateSignature::verify_aggregate`
+    // (BLS, built on the undeclared `bls12_381` dependency — see `bls_aggregate`'s module doc) and
+    // can't actually run in this tree: there is no `Cargo.toml` anywhere but `fuzz/Cargo.toml` to add
+    // `bls12_381` to, so the crate itself can't compile. Kept here, written as if the dependency were
+    // declared, so the coverage exists the moment that gap is closed.
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std", not(feature = "ffi_import")))]
+    fn bls_aggregate_round_trips() {
+        let signers = core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsSmall))
+            .take(4)
+            .collect::<Vec<_>>();
+        let message = 42u64;
+        let signatures = signers
+            .iter()
+            .map(|key_pair| SignatureOf::new(key_pair, &message))
+            .collect::<SignaturesOf<u64>>();
+
+        let aggregate = signatures.aggregate().unwrap();
+        let public_keys = signers
+            .iter()
+            .map(KeyPair::public_key)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        aggregate
+            .verify_aggregate(&public_keys, HashOf::new(&message))
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std", not(feature = "ffi_import")))]
+    fn bls_aggregate_rejects_mixed_algorithms() {
+        let bls_small = KeyPair::random_with_algorithm(Algorithm::BlsSmall);
+        let bls_normal = KeyPair::random_with_algorithm(Algorithm::BlsNormal);
+        let message = 42u64;
+        let signatures = [
+            SignatureOf::new(&bls_small, &message),
+            SignatureOf::new(&bls_normal, &message),
+        ]
+        .into_iter()
+        .collect::<SignaturesOf<u64>>();
+
+        assert!(signatures.aggregate().is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std", not(feature = "ffi_import")))]
+    fn bls_aggregate_rejects_tampered_key_set() {
+        let signers = core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsSmall))
+            .take(3)
+            .collect::<Vec<_>>();
+        let message = 42u64;
+        let signatures = signers
+            .iter()
+            .map(|key_pair| SignatureOf::new(key_pair, &message))
+            .collect::<SignaturesOf<u64>>();
+        let aggregate = signatures.aggregate().unwrap();
+
+        // Swap one signer's key for an unrelated one: the multi-pairing check must reject it.
+        let mut public_keys = signers
+            .iter()
+            .map(KeyPair::public_key)
+            .cloned()
+            .collect::<Vec<_>>();
+        public_keys[0] = KeyPair::random_with_algorithm(Algorithm::BlsSmall)
+            .public_key()
+            .clone();
+
+        assert!(aggregate
+            .verify_aggregate(&public_keys, HashOf::new(&message))
+            .is_err());
+    }
 }
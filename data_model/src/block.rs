@@ -6,11 +6,11 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, format, string::String, vec::Vec};
-use core::{cmp::Ordering, fmt::Display, time::Duration};
+use core::{cmp::Ordering, fmt::Display, marker::PhantomData, time::Duration};
 
 use derive_more::Display;
 use getset::Getters;
-use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignaturesOf};
+use iroha_crypto::{Hash, HashOf, KeyPair, MerkleTree, SignaturesOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -60,6 +60,12 @@ pub mod model {
         pub previous_block_hash: Option<HashOf<VersionedSignedBlock>>,
         /// Hash of merkle tree root of transactions' hashes.
         pub transactions_hash: Option<HashOf<MerkleTree<VersionedSignedTransaction>>>,
+        /// Hash of merkle tree root of attached blob sidecars' hashes.
+        ///
+        /// Covered by the block signatures so a sidecar served over a separate channel can be
+        /// authenticated against the block without trusting the serving peer. `None` when the
+        /// block carries no blobs.
+        pub blobs_hash: Option<HashOf<MerkleTree<BlobSidecar>>>,
         /// Topology of the network at the time of block commit.
         #[getset(skip)] // FIXME: Because ffi related issues
         pub commit_topology: Vec<peer::PeerId>,
@@ -69,6 +75,8 @@ pub mod model {
         pub consensus_estimation_ms: u64,
     }
 
+    /// Block payload
+    #[version_with_scale(version = 1, versioned_alias = "VersionedBlockPayload")]
     #[derive(
         Debug, Display, Clone, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
     )]
@@ -87,6 +95,30 @@ pub mod model {
         pub event_recommendations: Vec<Event>,
     }
 
+    /// Opaque bulky data committed to by [`BlockHeader::blobs_hash`] but carried outside the
+    /// signed block, so large attachments don't bloat the gossiped/stored block.
+    ///
+    /// Sidecars are served on a separate channel from the block itself and authenticated
+    /// against the header via [`BlobSidecar::verify`].
+    #[derive(
+        Debug, Display, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[display(fmt = "BlobSidecar#{index} of block {block_hash}")]
+    #[getset(get = "pub")]
+    #[allow(missing_docs)]
+    #[ffi_type]
+    pub struct BlobSidecar {
+        /// Hash of the block this sidecar belongs to.
+        pub block_hash: HashOf<VersionedSignedBlock>,
+        /// Index of this sidecar among the block's blobs (its leaf position in the tree).
+        pub index: u64,
+        /// Opaque payload.
+        #[getset(skip)]
+        pub data: Vec<u8>,
+        /// Merkle inclusion proof against [`BlockHeader::blobs_hash`].
+        pub proof: MerkleProof<BlobSidecar>,
+    }
+
     /// Signed block
     #[version_with_scale(version = 1, versioned_alias = "VersionedSignedBlock")]
     #[derive(
@@ -109,9 +141,9 @@ pub mod model {
     pub struct SignedBlock {
         /// Signatures of peers which approved this block.
         #[getset(skip)]
-        pub signatures: SignaturesOf<BlockPayload>,
-        /// Block payload
-        pub payload: BlockPayload,
+        pub signatures: SignaturesOf<VersionedBlockPayload, BlockSigningContext>,
+        /// Versioned block payload, signed over by `signatures`.
+        pub payload: VersionedBlockPayload,
     }
 }
 
@@ -120,6 +152,49 @@ declare_versioned!(VersionedSignedBlock 1..2, Debug, Clone, PartialEq, Eq, Parti
 #[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
 declare_versioned!(VersionedSignedBlock 1..2, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
 
+#[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
+declare_versioned!(VersionedBlockPayload 1..2, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
+#[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
+declare_versioned!(VersionedBlockPayload 1..2, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
+
+/// Whether a node accepts block payload versions other than `V1`.
+///
+/// Following Solana's versioned-transaction rollout, payload versions other than `V1` are gated
+/// off at [`validate()`](SignedBlock) time until this flag is flipped. [`VersionedBlockPayload`]
+/// declares only `V1`, so a payload of any other version fails to decode and never reaches that
+/// check.
+const ACCEPT_NON_V1_PAYLOAD: bool = false;
+
+/// Domain-separation context for block signatures, so a block signature can't be replayed as a
+/// valid signature over any other message type.
+///
+/// A dedicated marker type rather than an `impl SigningContext for VersionedBlockPayload`: the
+/// `Ctx` parameter of [`SignatureOf`](iroha_crypto::SignatureOf)/[`SignaturesOf`] is independent of
+/// the signed type, so opting blocks in here doesn't require every other signed type in the crate
+/// to grow a [`SigningContext`](iroha_crypto::SigningContext) impl of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSigningContext;
+
+impl iroha_crypto::SigningContext for BlockSigningContext {
+    const DOMAIN_TAG: &'static [u8] = b"iroha/block/v1";
+}
+
+impl VersionedBlockPayload {
+    /// Reference to the block header, regardless of payload version.
+    pub fn header(&self) -> &BlockHeader {
+        match self {
+            VersionedBlockPayload::V1(payload) => payload.header(),
+        }
+    }
+
+    /// Reference to the `V1` payload.
+    // FIXME: Remove once consumers are version-aware and stop assuming `V1`.
+    pub fn as_v1(&self) -> &BlockPayload {
+        let VersionedBlockPayload::V1(payload) = self;
+        payload
+    }
+}
+
 // TODO: Think about how should BlockPayload implement Eq, Ord?
 impl PartialEq for BlockPayload {
     fn eq(&self, other: &Self) -> bool {
@@ -143,6 +218,260 @@ impl BlockPayload {
     pub fn hash(&self) -> iroha_crypto::HashOf<Self> {
         iroha_crypto::HashOf::new(self)
     }
+
+    /// Build a Merkle inclusion proof for the transaction identified by `tx_hash`.
+    ///
+    /// Returns `None` if the block contains no such transaction. The proof can be checked
+    /// against [`BlockHeader::transactions_hash`] with [`MerkleProof::verify`], letting a light
+    /// client on the block stream confirm membership without rehashing every transaction.
+    #[cfg(feature = "std")]
+    pub fn transaction_inclusion_proof(
+        &self,
+        tx_hash: HashOf<VersionedSignedTransaction>,
+    ) -> Option<MerkleProof<VersionedSignedTransaction>> {
+        let leaves = self
+            .transactions
+            .iter()
+            .map(TransactionValue::hash)
+            .collect::<Vec<_>>();
+        let leaf_index = leaves.iter().position(|hash| *hash == tx_hash)?;
+        Some(MerkleProof::generate(&leaves, leaf_index))
+    }
+}
+
+/// Fold two sibling node hashes into their parent, exactly as [`MerkleTree`] does while building
+/// its own levels.
+///
+/// This was previously delegated to an inherent `MerkleTree::combine` kept in its own
+/// `crypto/src/merkle.rs` file, but that file was never wired up via a `mod merkle;` declaration
+/// in `iroha_crypto`'s crate root, so the inherent impl it defined never actually compiled into
+/// the crate. Folded back in here, next to the only caller, until that module is properly
+/// declared.
+fn combine(left: Hash, right: Hash) -> Hash {
+    let mut bytes = Vec::with_capacity(left.as_ref().len() + right.as_ref().len());
+    bytes.extend_from_slice(left.as_ref());
+    bytes.extend_from_slice(right.as_ref());
+    Hash::new(bytes)
+}
+
+/// Merkle inclusion proof: the ordered sibling hashes along the path from a leaf to the root,
+/// together with the leaf's index and the total leaf count (needed to reconstruct odd-width
+/// levels).
+///
+/// Folding is delegated to [`combine`] rather than reimplemented per call site, so a proof can
+/// never fold up to a root different from what the tree that produced it would compute.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct MerkleProof<T> {
+    leaf_index: u64,
+    leaf_count: u64,
+    siblings: Vec<Hash>,
+    #[codec(skip)]
+    #[serde(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T> MerkleProof<T> {
+    /// Generate a proof for `leaf_index` over the given ordered `leaves`.
+    #[cfg(feature = "std")]
+    fn generate(leaves: &[HashOf<T>], leaf_index: usize) -> Self {
+        let leaf_count = leaves.len();
+        let mut siblings = Vec::new();
+        let mut level: Vec<Hash> = leaves.iter().copied().map(Hash::from).collect();
+        let mut index = leaf_index;
+
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity((level.len() + 1) / 2);
+            let mut i = 0;
+            while i < level.len() {
+                if i + 1 < level.len() {
+                    if index == i {
+                        siblings.push(level[i + 1]);
+                    } else if index == i + 1 {
+                        siblings.push(level[i]);
+                    }
+                    next.push(combine(level[i], level[i + 1]));
+                } else {
+                    // Odd width: the last node is promoted unchanged, contributing no sibling.
+                    next.push(level[i]);
+                }
+                i += 2;
+            }
+            index /= 2;
+            level = next;
+        }
+
+        Self {
+            leaf_index: leaf_index as u64,
+            leaf_count: leaf_count as u64,
+            siblings,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The index of the proven leaf within the tree.
+    pub fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// The ordered sibling hashes from the leaf up to the root.
+    pub fn siblings(&self) -> &[Hash] {
+        &self.siblings
+    }
+
+    /// Recompute the root from `leaf_hash` by folding in each sibling (respecting the left/right
+    /// order implied by the leaf index at every level) and compare it against `root`.
+    pub fn verify(&self, leaf_hash: HashOf<T>, root: HashOf<MerkleTree<T>>) -> bool {
+        let mut hash = Hash::from(leaf_hash);
+        let mut index = usize::try_from(self.leaf_index).expect("leaf_index fits in usize");
+        let mut width = usize::try_from(self.leaf_count).expect("leaf_count fits in usize");
+        let mut siblings = self.siblings.iter();
+
+        while width > 1 {
+            let promoted = width % 2 == 1 && index == width - 1;
+            if !promoted {
+                let Some(&sibling) = siblings.next() else {
+                    return false;
+                };
+                hash = if index % 2 == 0 {
+                    combine(hash, sibling)
+                } else {
+                    combine(sibling, hash)
+                };
+            }
+            index /= 2;
+            width = (width + 1) / 2;
+        }
+
+        // Every recorded sibling must have been consumed by a real level.
+        siblings.next().is_none() && Hash::from(root) == hash
+    }
+}
+
+/// Aggregated block commit signature: a single signature over the block payload combining the
+/// per-peer signatures, plus a participation bitfield indexed against
+/// [`BlockHeader::commit_topology`].
+///
+/// Taking the approach of BLS-based consensus clients, this replaces the linearly-growing
+/// [`SignaturesOf`] set with a constant-size signature on the wire. Decoding stays backward
+/// compatible because this is a distinct, feature-gated container rather than a change to the
+/// existing signature set.
+#[cfg(feature = "bls-aggregate")]
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct AggregateBlockSignature {
+    /// Aggregate signature over the versioned block payload.
+    signature: iroha_crypto::AggregateSignature<VersionedBlockPayload, BlockSigningContext>,
+    /// Little-endian bit `i` set iff the `i`-th peer in `commit_topology` participated.
+    participants: Vec<u8>,
+}
+
+#[cfg(feature = "bls-aggregate")]
+impl AggregateBlockSignature {
+    fn is_participant(&self, index: usize) -> bool {
+        self.participants
+            .get(index / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    fn set_participant(&mut self, index: usize) {
+        let byte = index / 8;
+        if byte >= self.participants.len() {
+            self.participants.resize(byte + 1, 0);
+        }
+        self.participants[byte] |= 1 << (index % 8);
+    }
+}
+
+#[cfg(feature = "bls-aggregate")]
+impl VersionedSignedBlock {
+    /// Aggregate the block's individual commit signatures into a single [`AggregateBlockSignature`].
+    ///
+    /// The participation bitfield is built against `header.commit_topology`, so verification can
+    /// later select exactly the subset of public keys that signed.
+    ///
+    /// # Errors
+    /// Fails if the contained signatures can't be BLS-aggregated (see
+    /// [`SignaturesOf::aggregate`]).
+    #[cfg(feature = "std")]
+    pub fn sign_aggregate(&self) -> Result<AggregateBlockSignature, iroha_crypto::error::Error> {
+        let topology = &self.payload().header().commit_topology;
+        let mut participants = AggregateBlockSignature {
+            signature: self.signatures().aggregate()?,
+            participants: Vec::new(),
+        };
+        for signature in self.signatures() {
+            if let Some(index) = topology
+                .iter()
+                .position(|peer| peer.public_key() == signature.public_key())
+            {
+                participants.set_participant(index);
+            }
+        }
+        Ok(participants)
+    }
+
+    /// Fold an additional individual signature into an existing aggregate.
+    ///
+    /// # Errors
+    /// Fails if the signer isn't in `commit_topology` or the signature doesn't aggregate.
+    #[cfg(feature = "std")]
+    pub fn add_signature_aggregate(
+        &self,
+        aggregate: &mut AggregateBlockSignature,
+        signature: iroha_crypto::SignatureOf<VersionedBlockPayload, BlockSigningContext>,
+    ) -> Result<(), iroha_crypto::error::Error> {
+        let topology = &self.payload().header().commit_topology;
+        let index = topology
+            .iter()
+            .position(|peer| peer.public_key() == signature.public_key())
+            .ok_or_else(iroha_crypto::error::Error::other_signer)?;
+        aggregate.signature = aggregate.signature.aggregate_with(&signature)?;
+        aggregate.set_participant(index);
+        Ok(())
+    }
+
+    /// Verify an [`AggregateBlockSignature`] against the subset of `commit_topology` public keys
+    /// selected by its participation bitfield, in a single aggregate check.
+    ///
+    /// # Errors
+    /// Fails if the aggregate doesn't verify against the selected keys.
+    #[cfg(feature = "std")]
+    pub fn validate_aggregate(
+        &self,
+        aggregate: &AggregateBlockSignature,
+    ) -> Result<(), iroha_crypto::error::Error> {
+        let topology = &self.payload().header().commit_topology;
+        let signers = topology
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| aggregate.is_participant(*index))
+            .map(|(_, peer)| peer.public_key().clone())
+            .collect::<Vec<_>>();
+        aggregate
+            .signature
+            .verify_aggregate(&signers, iroha_crypto::HashOf::new(self.versioned_payload()))
+    }
+}
+
+impl BlobSidecar {
+    /// Leaf hash of this sidecar, derived from its opaque `data`.
+    ///
+    /// The `proof` field is excluded so the leaf a proof authenticates is stable.
+    #[cfg(feature = "std")]
+    pub fn leaf_hash(&self) -> HashOf<Self> {
+        HashOf::from_untyped_unchecked(Hash::new(&self.data))
+    }
+
+    /// Authenticate this sidecar against a block header's `blobs_hash`.
+    ///
+    /// Returns `false` if the header commits to no blobs or the inclusion proof doesn't fold up
+    /// to the committed root.
+    #[cfg(feature = "std")]
+    pub fn verify(&self, blobs_hash: Option<HashOf<MerkleTree<Self>>>) -> bool {
+        match blobs_hash {
+            Some(root) => self.proof.verify(self.leaf_hash(), root),
+            None => false,
+        }
+    }
 }
 
 impl BlockHeader {
@@ -171,23 +500,28 @@ impl SignedBlock {
 }
 
 impl VersionedSignedBlock {
-    /// Block payload
-    // FIXME: Leaking concrete type BlockPayload from Versioned container. Payload should be versioned
-    pub fn payload(&self) -> &BlockPayload {
+    /// Versioned block payload
+    pub fn versioned_payload(&self) -> &VersionedBlockPayload {
         let VersionedSignedBlock::V1(block) = self;
         block.payload()
     }
 
+    /// Block payload
+    pub fn payload(&self) -> &BlockPayload {
+        self.versioned_payload().as_v1()
+    }
+
     /// Used to inject faulty payload for testing
     #[cfg(debug_assertions)]
     #[cfg(feature = "transparent_api")]
     pub fn payload_mut(&mut self) -> &mut BlockPayload {
         let VersionedSignedBlock::V1(block) = self;
-        &mut block.payload
+        let VersionedBlockPayload::V1(payload) = &mut block.payload;
+        payload
     }
 
     /// Signatures of peers which approved this block.
-    pub fn signatures(&self) -> &SignaturesOf<BlockPayload> {
+    pub fn signatures(&self) -> &SignaturesOf<VersionedBlockPayload, BlockSigningContext> {
         let VersionedSignedBlock::V1(block) = self;
         &block.signatures
     }
@@ -206,7 +540,7 @@ impl VersionedSignedBlock {
     #[cfg(feature = "std")]
     #[cfg(feature = "transparent_api")]
     pub fn sign(mut self, key_pair: KeyPair) -> Result<Self, iroha_crypto::error::Error> {
-        iroha_crypto::SignatureOf::new(key_pair, self.payload()).map(|signature| {
+        iroha_crypto::SignatureOf::new(key_pair, self.versioned_payload()).map(|signature| {
             let VersionedSignedBlock::V1(block) = &mut self;
             block.signatures.insert(signature);
             self
@@ -222,9 +556,9 @@ impl VersionedSignedBlock {
     #[cfg(feature = "transparent_api")]
     pub fn add_signature(
         &mut self,
-        signature: iroha_crypto::SignatureOf<BlockPayload>,
+        signature: iroha_crypto::SignatureOf<VersionedBlockPayload, BlockSigningContext>,
     ) -> Result<(), iroha_crypto::error::Error> {
-        signature.verify(self.payload())?;
+        signature.verify(self.versioned_payload())?;
 
         let VersionedSignedBlock::V1(block) = self;
         block.signatures.insert(signature);
@@ -237,7 +571,7 @@ impl VersionedSignedBlock {
     #[cfg(feature = "transparent_api")]
     pub fn replace_signatures(
         &mut self,
-        signatures: iroha_crypto::SignaturesOf<BlockPayload>,
+        signatures: iroha_crypto::SignaturesOf<VersionedBlockPayload, BlockSigningContext>,
     ) -> bool {
         #[cfg(not(feature = "std"))]
         use alloc::collections::BTreeSet;
@@ -264,18 +598,19 @@ mod candidate {
 
     #[derive(Decode, Deserialize)]
     struct SignedBlockCandidate {
-        signatures: SignaturesOf<BlockPayload>,
-        payload: BlockPayload,
+        signatures: SignaturesOf<VersionedBlockPayload, BlockSigningContext>,
+        payload: VersionedBlockPayload,
     }
 
     impl SignedBlockCandidate {
         fn validate(self) -> Result<SignedBlock, &'static str> {
+            self.validate_version()?;
             #[cfg(feature = "std")]
             self.validate_signatures()?;
             #[cfg(feature = "std")]
             self.validate_header()?;
 
-            if self.payload.transactions.is_empty() {
+            if self.payload.as_v1().transactions.is_empty() {
                 return Err("Block is empty");
             }
 
@@ -285,12 +620,25 @@ mod candidate {
             })
         }
 
+        /// Reject payload versions other than `V1` unless [`ACCEPT_NON_V1_PAYLOAD`] is set. Only
+        /// `V1` is declared, so other versions already fail to decode and the gated arms are
+        /// unreachable.
+        fn validate_version(&self) -> Result<(), &'static str> {
+            match self.payload {
+                VersionedBlockPayload::V1(_) => Ok(()),
+                #[allow(unreachable_patterns)]
+                _ if ACCEPT_NON_V1_PAYLOAD => Ok(()),
+                #[allow(unreachable_patterns)]
+                _ => Err("Unsupported block payload version"),
+            }
+        }
+
         #[cfg(feature = "std")]
         fn validate_header(&self) -> Result<(), &'static str> {
-            let actual_txs_hash = self.payload.header().transactions_hash;
+            let payload = self.payload.as_v1();
+            let actual_txs_hash = payload.header().transactions_hash;
 
-            let expected_txs_hash = self
-                .payload
+            let expected_txs_hash = payload
                 .transactions
                 .iter()
                 .map(TransactionValue::hash)
@@ -358,15 +706,58 @@ pub mod stream {
 
         use super::*;
 
-        /// Request sent to subscribe to blocks stream starting from the given height.
-        #[derive(Debug, Clone, Copy, Constructor, Decode, Encode, IntoSchema)]
-        #[repr(transparent)]
-        pub struct BlockSubscriptionRequest(pub NonZeroU64);
+        /// Request sent to subscribe to the block stream.
+        ///
+        /// Beyond a start height this carries an optional end height (for bounded historical
+        /// sync that completes and closes), a confirmation depth (to receive only sufficiently
+        /// buried, committed-only blocks), and a resume token echoed by a reconnecting client so
+        /// the producer can reject a stale cursor on a reorged chain instead of silently
+        /// resuming.
+        #[derive(Debug, Clone, Copy, Decode, Encode, IntoSchema)]
+        pub struct BlockSubscriptionRequest {
+            /// Height to start streaming from.
+            pub from_height: NonZeroU64,
+            /// Inclusive end height. When set, the stream completes and closes after it;
+            /// otherwise it tails indefinitely.
+            pub to_height: Option<NonZeroU64>,
+            /// Deliver only blocks buried under at least this many confirmations. `0` streams
+            /// blocks as soon as they are committed.
+            pub confirmation_depth: u64,
+            /// Hash the client expects the block at `from_height - 1` to have. When set and it
+            /// disagrees with the producer's chain, the subscription is rejected as stale.
+            pub resume_token: Option<HashOf<VersionedSignedBlock>>,
+        }
 
         /// Message sent by the stream producer containing block.
+        ///
+        /// The block's own [`hash`](VersionedSignedBlock::hash) serves as the resume token a
+        /// reconnecting client echoes back in [`BlockSubscriptionRequest::resume_token`].
         #[derive(Debug, Clone, Decode, Encode, IntoSchema)]
         #[repr(transparent)]
         pub struct BlockMessage(pub VersionedSignedBlock);
+
+        /// Request sent to subscribe to the blob sidecars of the block at the given height.
+        #[derive(Debug, Clone, Copy, Constructor, Decode, Encode, IntoSchema)]
+        #[repr(transparent)]
+        pub struct BlobSubscriptionRequest(pub NonZeroU64);
+
+        /// Message sent by the stream producer containing a single blob sidecar.
+        #[derive(Debug, Clone, Decode, Encode, IntoSchema)]
+        #[repr(transparent)]
+        pub struct BlobMessage(pub BlobSidecar);
+    }
+
+    impl BlockSubscriptionRequest {
+        /// Subscribe to an open-ended live stream starting at `from_height`, with no
+        /// confirmation-depth requirement and no resume token.
+        pub const fn new(from_height: core::num::NonZeroU64) -> Self {
+            Self {
+                from_height,
+                to_height: None,
+                confirmation_depth: 0,
+                resume_token: None,
+            }
+        }
     }
 
     impl From<BlockMessage> for VersionedSignedBlock {
@@ -375,9 +766,17 @@ pub mod stream {
         }
     }
 
+    impl From<BlobMessage> for BlobSidecar {
+        fn from(source: BlobMessage) -> Self {
+            source.0
+        }
+    }
+
     /// Exports common structs and enums from this module.
     pub mod prelude {
-        pub use super::{BlockMessage, BlockSubscriptionRequest};
+        pub use super::{
+            BlobMessage, BlobSubscriptionRequest, BlockMessage, BlockSubscriptionRequest,
+        };
     }
 }
 
@@ -0,0 +1,17 @@
+//! Fuzz the untrusted on-disk block decode path.
+//!
+//! This feeds arbitrary bytes to [`VersionedSignedBlock::decode_all_versioned`] — the exact
+//! call the Kura inspector makes on `blocks.data` — so decode panics, integer overflow on
+//! `BlockIndex` lengths, and allocation blow-ups from attacker-controlled length prefixes are
+//! caught before they reach a running peer. Seed corpus lives in `corpus/block_decode/` and is
+//! derived from real SCALE-encoded blocks.
+#![no_main]
+
+use iroha_data_model::block::VersionedSignedBlock;
+use iroha_version::scale::DecodeVersioned;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // A malformed stream must return `Err`, never panic, overflow, or over-allocate.
+    let _ = VersionedSignedBlock::decode_all_versioned(data);
+});
@@ -0,0 +1,17 @@
+//! Fuzz the event-subscription handshake decode path.
+//!
+//! This feeds arbitrary WebSocket frame payloads into the
+//! [`EventSubscriptionRequest`]/[`FilterBox`] conversion that [`Consumer::new`] performs on the
+//! first message of a connection, so malformed subscription messages from an untrusted client
+//! can't panic the node. Seed corpus lives in `corpus/event_subscription/` and is derived from
+//! real encoded subscription requests.
+#![no_main]
+
+use iroha_data_model::events::prelude::EventSubscriptionRequest;
+use iroha_version::scale::DecodeVersioned;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Decoding an arbitrary frame as a subscription request must fail gracefully.
+    let _ = EventSubscriptionRequest::decode_all_versioned(data);
+});